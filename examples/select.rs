@@ -1,14 +1,14 @@
-use std::{error::Error, ops::ControlFlow, time::Duration};
+use std::{error::Error, time::Duration};
 
 use tokio::{
-    select,
     signal::unix::{signal, SignalKind},
     sync::mpsc,
     time,
 };
+use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::sync::CancellationToken;
 
-use option_into_controlflow::OptionExt as _;
+use into_controlflow::StreamExt as _;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
@@ -25,13 +25,13 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-async fn process_messages(mut msgs: mpsc::Receiver<i32>, token: CancellationToken) {
-    while let ControlFlow::Continue(msg) = select! { biased;
-        _ = token.cancelled() => ControlFlow::Break(()),
-        m = msgs.recv() => m.continue_or(()),
-    } {
-        println!("msg = {}", msg)
-    }
+async fn process_messages(msgs: mpsc::Receiver<i32>, token: CancellationToken) {
+    let _ = ReceiverStream::new(msgs)
+        .for_each_continue_with_cancel(token.cancelled(), |msg| {
+            println!("msg = {}", msg);
+            std::ops::ControlFlow::<()>::Continue(())
+        })
+        .await;
 }
 
 fn messages() -> mpsc::Receiver<i32> {
@@ -1,4 +1,88 @@
-use std::ops::ControlFlow;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::ops::ControlFlow;
+
+#[cfg(all(feature = "async", feature = "std"))]
+mod stream;
+#[cfg(all(feature = "async", feature = "std"))]
+pub use stream::{Stopped, StreamExt};
+
+/// Evaluates a [`ControlFlow<B, C>`] expression, yielding the `C` on [`Continue(c)`] or
+/// returning [`Break(b)`] from the enclosing function on [`Break(b)`].
+///
+/// This emulates the `?` operator for [`ControlFlow`], whose [`Try`] implementation is still
+/// unstable. The enclosing function must itself return a [`ControlFlow<B, _>`] with the same
+/// `B`.
+///
+/// [`Continue(c)`]: ControlFlow::Continue
+/// [`Break(b)`]: ControlFlow::Break
+/// [`Try`]: std::ops::Try
+///
+/// # Examples
+///
+/// ```
+/// # use core::ops::ControlFlow;
+/// use into_controlflow::cf_try;
+///
+/// fn step(x: i32) -> ControlFlow<&'static str, i32> {
+///     if x < 0 {
+///         ControlFlow::Break("negative")
+///     } else {
+///         ControlFlow::Continue(x)
+///     }
+/// }
+///
+/// fn run(x: i32, y: i32) -> ControlFlow<&'static str, i32> {
+///     let x = cf_try!(step(x));
+///     let y = cf_try!(step(y));
+///     ControlFlow::Continue(x + y)
+/// }
+///
+/// assert_eq!(run(1, 2), ControlFlow::Continue(3));
+/// assert_eq!(run(-1, 2), ControlFlow::Break("negative"));
+/// ```
+#[macro_export]
+macro_rules! cf_try {
+    ($e:expr) => {
+        match $e {
+            ::core::ops::ControlFlow::Continue(c) => c,
+            ::core::ops::ControlFlow::Break(b) => return ::core::ops::ControlFlow::Break(b),
+        }
+    };
+}
+
+/// Evaluates an [`Option<T>`] expression, yielding the `T` on [`Some(v)`] or returning
+/// [`Break(b())`] from the enclosing function on [`None`].
+///
+/// This is [`cf_try!`] for [`Option`], using [`OptionExt::continue_or_else`] to convert the
+/// `Option` into a `ControlFlow` before propagating it.
+///
+/// [`Some(v)`]: Some
+/// [`Break(b())`]: ControlFlow::Break
+///
+/// # Examples
+///
+/// ```
+/// # use core::ops::ControlFlow;
+/// use into_controlflow::cf_try_opt;
+///
+/// fn first_char(s: &str) -> ControlFlow<&'static str, char> {
+///     let c = cf_try_opt!(s.chars().next(), || "empty");
+///     ControlFlow::Continue(c)
+/// }
+///
+/// assert_eq!(first_char("foo"), ControlFlow::Continue('f'));
+/// assert_eq!(first_char(""), ControlFlow::Break("empty"));
+/// ```
+#[macro_export]
+macro_rules! cf_try_opt {
+    ($e:expr, $b:expr) => {
+        $crate::cf_try!($crate::OptionExt::continue_or_else($e, $b))
+    };
+}
 
 pub trait OptionExt {
     /// The type wrapped by [`Option`]
@@ -14,7 +98,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -37,7 +121,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -63,7 +147,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -88,7 +172,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -119,7 +203,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -144,7 +228,7 @@ pub trait OptionExt {
     /// # Examples
     ///
     /// ```
-    /// # use std::ops::ControlFlow;
+    /// # use core::ops::ControlFlow;
     /// use into_controlflow::OptionExt as _;
     ///
     /// let x = Some("foo");
@@ -187,11 +271,466 @@ impl<T> OptionExt for Option<T> {
     }
 }
 
-#[cfg(test)]
+pub trait ResultExt {
+    /// The success type wrapped by [`Result`]
+    type Ok;
+    /// The error type wrapped by [`Result`]
+    type Err;
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<B, T>`], mapping [`Ok(v)`] to
+    /// [`Continue(v)`] and [`Err(e)`] to [`Break(b(e))`].
+    ///
+    /// [`Continue(v)`]: ControlFlow::Continue
+    /// [`Break(b(e))`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.continue_ok_or_else(|_| 0), ControlFlow::Continue("foo"));
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.continue_ok_or_else(|_| 0), ControlFlow::Break(0));
+    /// ```
+    fn continue_ok_or_else<B, F>(self, b: F) -> ControlFlow<B, Self::Ok>
+    where
+        F: FnOnce(Self::Err) -> B;
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<E, C>`], mapping [`Err(e)`] to
+    /// [`Break(e)`] and [`Ok(v)`] to [`Continue(c(v))`].
+    ///
+    /// [`Continue(c(v))`]: ControlFlow::Continue
+    /// [`Break(e)`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.break_err_or_else(|_| 0), ControlFlow::Break("bar"));
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.break_err_or_else(|_| 0), ControlFlow::Continue(0));
+    /// ```
+    fn break_err_or_else<C, F>(self, c: F) -> ControlFlow<Self::Err, C>
+    where
+        F: FnOnce(Self::Ok) -> C;
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<E, T>`], mapping [`Ok(v)`] to
+    /// [`Continue(v)`] and [`Err(e)`] to [`Break(e)`].
+    ///
+    /// Unlike [`continue_ok_or_else`], this keeps the error untouched on the [`Break`] side
+    /// instead of transforming it.
+    ///
+    /// [`Continue(v)`]: ControlFlow::Continue
+    /// [`Break(e)`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    /// [`continue_ok_or_else`]: ResultExt::continue_ok_or_else
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.continue_ok_keep_err(), ControlFlow::Continue("foo"));
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.continue_ok_keep_err(), ControlFlow::Break("bar"));
+    /// ```
+    fn continue_ok_keep_err(self) -> ControlFlow<Self::Err, Self::Ok>
+    where
+        Self: Sized;
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<B, T>`], mapping [`Ok(v)`] to
+    /// [`Continue(v)`] and [`Err(e)`] to [`Break(b)`], discarding the error.
+    ///
+    /// A non-lazy version of [`continue_ok_or_else`].
+    ///
+    /// [`Continue(v)`]: ControlFlow::Continue
+    /// [`Break(b)`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    /// [`continue_ok_or_else`]: ResultExt::continue_ok_or_else
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.continue_ok_or(0), ControlFlow::Continue("foo"));
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.continue_ok_or(0), ControlFlow::Break(0));
+    /// ```
+    fn continue_ok_or<B>(self, b: B) -> ControlFlow<B, Self::Ok>
+    where
+        Self: Sized,
+    {
+        self.continue_ok_or_else(|_| b)
+    }
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<B, T>`], mapping [`Ok(v)`] to
+    /// [`Continue(v)`] and [`Err(e)`] to the default value of `B`.
+    ///
+    /// [`Continue(v)`]: ControlFlow::Continue
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// let y: ControlFlow<i32, _> = x.continue_ok_or_default();
+    /// assert_eq!(y, ControlFlow::Continue("foo"));
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.continue_ok_or_default(), ControlFlow::Break(0));
+    /// ```
+    fn continue_ok_or_default<B>(self) -> ControlFlow<B, Self::Ok>
+    where
+        Self: Sized,
+        B: Default,
+    {
+        self.continue_ok_or_else(|_| B::default())
+    }
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<E, C>`], mapping [`Err(e)`] to
+    /// [`Break(e)`] and [`Ok(v)`] to [`Continue(c)`], discarding the value.
+    ///
+    /// A non-lazy version of [`break_err_or_else`].
+    ///
+    /// [`Continue(c)`]: ControlFlow::Continue
+    /// [`Break(e)`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    /// [`break_err_or_else`]: ResultExt::break_err_or_else
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// assert_eq!(x.break_err_or(0), ControlFlow::Break("bar"));
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.break_err_or(0), ControlFlow::Continue(0));
+    /// ```
+    fn break_err_or<C>(self, c: C) -> ControlFlow<Self::Err, C>
+    where
+        Self: Sized,
+    {
+        self.break_err_or_else(|_| c)
+    }
+
+    /// Transforms the [`Result<T, E>`] into a [`ControlFlow<E, C>`], mapping [`Err(e)`] to
+    /// [`Break(e)`] and [`Ok(v)`] to the default value of `C`.
+    ///
+    /// [`Break(e)`]: ControlFlow::Break
+    /// [`Ok(v)`]: Ok
+    /// [`Err(e)`]: Err
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ResultExt as _;
+    ///
+    /// let x: Result<&str, &str> = Err("bar");
+    /// let y: ControlFlow<_, i32> = x.break_err_or_default();
+    /// assert_eq!(y, ControlFlow::Break("bar"));
+    ///
+    /// let x: Result<&str, &str> = Ok("foo");
+    /// assert_eq!(x.break_err_or_default(), ControlFlow::Continue(0));
+    /// ```
+    fn break_err_or_default<C>(self) -> ControlFlow<Self::Err, C>
+    where
+        Self: Sized,
+        C: Default,
+    {
+        self.break_err_or_else(|_| C::default())
+    }
+}
+
+pub trait ControlFlowExt {
+    /// The type carried by [`Continue`]
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    type Continue;
+    /// The type carried by [`Break`]
+    ///
+    /// [`Break`]: ControlFlow::Break
+    type Break;
+
+    /// Converts the [`ControlFlow<B, C>`] into an [`Option<B>`], discarding the [`Continue`]
+    /// value.
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.into_break(), Some(1));
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.into_break(), None);
+    /// ```
+    fn into_break(self) -> Option<Self::Break>;
+
+    /// Converts the [`ControlFlow<B, C>`] into an [`Option<C>`], discarding the [`Break`] value.
+    ///
+    /// [`Break`]: ControlFlow::Break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.into_continue(), Some("foo"));
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.into_continue(), None);
+    /// ```
+    fn into_continue(self) -> Option<Self::Continue>;
+
+    /// Collapses the [`ControlFlow<B, C>`] into a `C`, mapping [`Break(b)`] through `f` and
+    /// leaving [`Continue(c)`] untouched.
+    ///
+    /// [`Continue(c)`]: ControlFlow::Continue
+    /// [`Break(b)`]: ControlFlow::Break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.continue_or_else(|_| "bar"), "foo");
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.continue_or_else(|b| if b == 1 { "bar" } else { "baz" }), "bar");
+    /// ```
+    fn continue_or_else<F>(self, f: F) -> Self::Continue
+    where
+        F: FnOnce(Self::Break) -> Self::Continue;
+
+    /// Collapses the [`ControlFlow<B, C>`] into a `B`, mapping [`Continue(c)`] through `f` and
+    /// leaving [`Break(b)`] untouched.
+    ///
+    /// [`Continue(c)`]: ControlFlow::Continue
+    /// [`Break(b)`]: ControlFlow::Break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.break_or_else(|_| 0), 1);
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.break_or_else(|c| c.len() as i32), 3);
+    /// ```
+    fn break_or_else<F>(self, f: F) -> Self::Break
+    where
+        F: FnOnce(Self::Continue) -> Self::Break;
+
+    /// Maps the [`Continue`] value of the [`ControlFlow`] with `f`, leaving a [`Break`]
+    /// untouched.
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    /// [`Break`]: ControlFlow::Break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.map_continue(|c| c.len()), ControlFlow::Continue(3));
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.map_continue(|c| c.len()), ControlFlow::Break(1));
+    /// ```
+    fn map_continue<C2, F>(self, f: F) -> ControlFlow<Self::Break, C2>
+    where
+        F: FnOnce(Self::Continue) -> C2;
+
+    /// Maps the [`Break`] value of the [`ControlFlow`] with `f`, leaving a [`Continue`]
+    /// untouched.
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    /// [`Break`]: ControlFlow::Break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.map_break(|b| b * 10), ControlFlow::Break(10));
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.map_break(|b| b * 10), ControlFlow::Continue("foo"));
+    /// ```
+    fn map_break<B2, F>(self, f: F) -> ControlFlow<B2, Self::Continue>
+    where
+        F: FnOnce(Self::Break) -> B2;
+
+    /// Transforms the [`ControlFlow<B, C>`] into a [`Result<C, B>`], mapping [`Continue(c)`] to
+    /// [`Ok(c)`] and [`Break(b)`] to [`Err(b)`].
+    ///
+    /// [`Continue(c)`]: ControlFlow::Continue
+    /// [`Break(b)`]: ControlFlow::Break
+    /// [`Ok(c)`]: Ok
+    /// [`Err(b)`]: Err
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// use into_controlflow::ControlFlowExt as _;
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+    /// assert_eq!(x.into_result(), Ok("foo"));
+    ///
+    /// let x: ControlFlow<i32, &str> = ControlFlow::Break(1);
+    /// assert_eq!(x.into_result(), Err(1));
+    /// ```
+    fn into_result(self) -> Result<Self::Continue, Self::Break>;
+}
+
+impl<B, C> ControlFlowExt for ControlFlow<B, C> {
+    type Continue = C;
+    type Break = B;
+
+    fn into_break(self) -> Option<B> {
+        match self {
+            ControlFlow::Break(b) => Some(b),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+
+    fn into_continue(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(c) => Some(c),
+            ControlFlow::Break(_) => None,
+        }
+    }
+
+    fn continue_or_else<F>(self, f: F) -> C
+    where
+        F: FnOnce(B) -> C,
+    {
+        match self {
+            ControlFlow::Continue(c) => c,
+            ControlFlow::Break(b) => f(b),
+        }
+    }
+
+    fn break_or_else<F>(self, f: F) -> B
+    where
+        F: FnOnce(C) -> B,
+    {
+        match self {
+            ControlFlow::Break(b) => b,
+            ControlFlow::Continue(c) => f(c),
+        }
+    }
+
+    fn map_continue<C2, F>(self, f: F) -> ControlFlow<B, C2>
+    where
+        F: FnOnce(C) -> C2,
+    {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(f(c)),
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+        }
+    }
+
+    fn map_break<B2, F>(self, f: F) -> ControlFlow<B2, C>
+    where
+        F: FnOnce(B) -> B2,
+    {
+        match self {
+            ControlFlow::Break(b) => ControlFlow::Break(f(b)),
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+        }
+    }
+
+    fn into_result(self) -> Result<C, B> {
+        match self {
+            ControlFlow::Continue(c) => Ok(c),
+            ControlFlow::Break(b) => Err(b),
+        }
+    }
+}
+
+impl<T, E> ResultExt for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn continue_ok_or_else<B, F>(self, b: F) -> ControlFlow<B, T>
+    where
+        F: FnOnce(E) -> B,
+    {
+        match self {
+            Ok(v) => ControlFlow::Continue(v),
+            Err(e) => ControlFlow::Break(b(e)),
+        }
+    }
+
+    fn break_err_or_else<C, F>(self, c: F) -> ControlFlow<E, C>
+    where
+        F: FnOnce(T) -> C,
+    {
+        match self {
+            Err(e) => ControlFlow::Break(e),
+            Ok(v) => ControlFlow::Continue(c(v)),
+        }
+    }
+
+    fn continue_ok_keep_err(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(v) => ControlFlow::Continue(v),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::ops::ControlFlow;
+    use core::ops::ControlFlow;
 
-    use crate::OptionExt as _;
+    use crate::{ControlFlowExt as _, OptionExt as _, ResultExt as _};
 
     #[test]
     fn test_value() {
@@ -241,4 +780,124 @@ mod tests {
         assert_eq!(Some(1).break_or_default::<()>(), ControlFlow::Break(1));
         assert_eq!(None::<i32>.break_or_default(), ControlFlow::Continue(0));
     }
+
+    #[test]
+    fn test_result_value() {
+        let ok: Result<i32, i32> = Ok(1);
+        let err: Result<i32, i32> = Err(2);
+        assert_eq!(ok.continue_ok_or(0), ControlFlow::Continue(1));
+        assert_eq!(err.continue_ok_or(0), ControlFlow::Break(0));
+        assert_eq!(ok.break_err_or(0), ControlFlow::Continue(0));
+        assert_eq!(err.break_err_or(0), ControlFlow::Break(2));
+    }
+
+    #[test]
+    fn test_result_or_else() {
+        let ok: Result<i32, i32> = Ok(1);
+        let err: Result<i32, i32> = Err(2);
+
+        let mut called = false;
+        assert_eq!(
+            ok.continue_ok_or_else(|_| {
+                called = true;
+                0
+            }),
+            ControlFlow::Continue(1)
+        );
+        assert!(!called);
+        assert_eq!(err.continue_ok_or_else(|e| e * 10), ControlFlow::Break(20));
+
+        let mut called = false;
+        assert_eq!(
+            err.break_err_or_else(|_| {
+                called = true;
+                0
+            }),
+            ControlFlow::Break(2)
+        );
+        assert!(!called);
+        assert_eq!(ok.break_err_or_else(|v| v * 10), ControlFlow::Continue(10));
+    }
+
+    #[test]
+    fn test_result_keep_err() {
+        let ok: Result<i32, i32> = Ok(1);
+        let err: Result<i32, i32> = Err(2);
+        assert_eq!(ok.continue_ok_keep_err(), ControlFlow::Continue(1));
+        assert_eq!(err.continue_ok_keep_err(), ControlFlow::Break(2));
+    }
+
+    #[test]
+    fn test_result_default() {
+        let ok: Result<i32, i32> = Ok(1);
+        let err: Result<i32, i32> = Err(2);
+        assert_eq!(ok.continue_ok_or_default::<()>(), ControlFlow::Continue(1));
+        assert_eq!(err.continue_ok_or_default(), ControlFlow::Break(0));
+        assert_eq!(ok.break_err_or_default::<()>(), ControlFlow::Continue(()));
+        assert_eq!(err.break_err_or_default::<()>(), ControlFlow::Break(2));
+    }
+
+    #[test]
+    fn test_controlflow_into() {
+        let cont: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+        let brk: ControlFlow<i32, &str> = ControlFlow::Break(1);
+        assert_eq!(cont.into_continue(), Some("foo"));
+        assert_eq!(cont.into_break(), None);
+        assert_eq!(brk.into_break(), Some(1));
+        assert_eq!(brk.into_continue(), None);
+        assert_eq!(cont.into_result(), Ok("foo"));
+        assert_eq!(brk.into_result(), Err(1));
+    }
+
+    #[test]
+    fn test_controlflow_collapse() {
+        let cont: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+        let brk: ControlFlow<i32, &str> = ControlFlow::Break(1);
+        assert_eq!(cont.continue_or_else(|_| "bar"), "foo");
+        assert_eq!(brk.continue_or_else(|_| "bar"), "bar");
+        assert_eq!(cont.break_or_else(|c| c.len() as i32), 3);
+        assert_eq!(brk.break_or_else(|c| c.len() as i32), 1);
+    }
+
+    #[test]
+    fn test_controlflow_map() {
+        let cont: ControlFlow<i32, &str> = ControlFlow::Continue("foo");
+        let brk: ControlFlow<i32, &str> = ControlFlow::Break(1);
+        assert_eq!(cont.map_continue(|c| c.len()), ControlFlow::Continue(3));
+        assert_eq!(brk.map_continue(|c| c.len()), ControlFlow::Break(1));
+        assert_eq!(brk.map_break(|b| b * 10), ControlFlow::Break(10));
+        assert_eq!(cont.map_break(|b| b * 10), ControlFlow::Continue("foo"));
+    }
+
+    fn try_step(x: i32) -> ControlFlow<&'static str, i32> {
+        if x < 0 {
+            ControlFlow::Break("negative")
+        } else {
+            ControlFlow::Continue(x)
+        }
+    }
+
+    #[test]
+    fn test_cf_try() {
+        fn run(x: i32, y: i32) -> ControlFlow<&'static str, i32> {
+            let x = crate::cf_try!(try_step(x));
+            let y = crate::cf_try!(try_step(y));
+            ControlFlow::Continue(x + y)
+        }
+
+        assert_eq!(run(1, 2), ControlFlow::Continue(3));
+        assert_eq!(run(-1, 2), ControlFlow::Break("negative"));
+        assert_eq!(run(1, -2), ControlFlow::Break("negative"));
+    }
+
+    #[test]
+    fn test_cf_try_opt() {
+        fn first_char(s: &str) -> ControlFlow<&'static str, char> {
+            let c = crate::cf_try_opt!(s.chars().next(), || "empty");
+            ControlFlow::Continue(c)
+        }
+
+        assert_eq!(first_char("foo"), ControlFlow::Continue('f'));
+        assert_eq!(first_char(""), ControlFlow::Break("empty"));
+    }
 }
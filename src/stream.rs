@@ -0,0 +1,79 @@
+use std::{future::Future, ops::ControlFlow, pin::pin};
+
+use futures::{Stream, StreamExt as _};
+use tokio::select;
+
+/// Why [`StreamExt::for_each_continue_with_cancel`] stopped driving the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stopped<B> {
+    /// The cancellation future resolved before the closure requested an early exit.
+    Cancelled,
+    /// The closure returned [`Break(b)`].
+    ///
+    /// [`Break(b)`]: ControlFlow::Break
+    Break(B),
+}
+
+/// Extension trait over [`Stream`], packaging the `while let ControlFlow::Continue(_) = select!
+/// { ... }` loop used to consume a cancellable channel.
+pub trait StreamExt: Stream {
+    /// Drives the stream to completion, calling `f` on each item.
+    ///
+    /// Resolves to [`Break(b)`] as soon as `f` returns [`Break(b)`], or to [`Continue(())`] once
+    /// the stream is exhausted.
+    ///
+    /// [`Break(b)`]: ControlFlow::Break
+    /// [`Continue(())`]: ControlFlow::Continue
+    fn for_each_continue<B, F>(self, f: F) -> impl Future<Output = ControlFlow<B, ()>>
+    where
+        Self: Sized + Unpin,
+        F: FnMut(Self::Item) -> ControlFlow<B, ()>,
+    {
+        async move {
+            let mut stream = self;
+            let mut f = f;
+            while let Some(item) = stream.next().await {
+                if let ControlFlow::Break(b) = f(item) {
+                    return ControlFlow::Break(b);
+                }
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Drives the stream to completion like [`for_each_continue`], but races it against
+    /// `cancel`, stopping early and resolving to [`Break(Stopped::Cancelled)`] if `cancel`
+    /// resolves first.
+    ///
+    /// [`for_each_continue`]: StreamExt::for_each_continue
+    /// [`Break(Stopped::Cancelled)`]: ControlFlow::Break
+    fn for_each_continue_with_cancel<B, F, C>(
+        self,
+        cancel: C,
+        f: F,
+    ) -> impl Future<Output = ControlFlow<Stopped<B>, ()>>
+    where
+        Self: Sized + Unpin,
+        F: FnMut(Self::Item) -> ControlFlow<B, ()>,
+        C: Future<Output = ()>,
+    {
+        async move {
+            let mut stream = self;
+            let mut f = f;
+            let mut cancel = pin!(cancel);
+            loop {
+                select! { biased;
+                    _ = &mut cancel => return ControlFlow::Break(Stopped::Cancelled),
+                    item = stream.next() => match item {
+                        Some(item) => if let ControlFlow::Break(b) = f(item) {
+                            return ControlFlow::Break(Stopped::Break(b));
+                        },
+                        None => return ControlFlow::Continue(()),
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl<S: Stream> StreamExt for S {}